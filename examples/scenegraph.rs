@@ -53,40 +53,10 @@ impl<ID: Clone + Eq + Hash + Ord + Debug> SceneGraph<ID> {
     }
 
     fn calculate_global_transforms(&self) -> HashMap<ID, Transform> {
-        let mut global_transforms = HashMap::new();
-
-        fn traverse<ID: Clone + Eq + Hash + Ord + Debug>(
-            graph: &NodeGraph<ID, (), Transform>,
-            node_id: &ID,
-            current_transform: Transform,
-            global_transforms: &mut HashMap<ID, Transform>,
-        ) {
-            if let Some(local_transform) = graph.node_data(&node_id.clone()) {
-                let global_transform = local_transform.aggregate(&current_transform);
-                global_transforms.insert(node_id.clone(), global_transform);
-
-                if let Some(children) = graph.get_edges_connected_to_node(node_id) {
-                    for (child_id, _) in children {
-                        traverse(graph, &child_id, global_transform, global_transforms);
-                    }
-                }
-            }
-        }
-
-        let root_transform = self
-            .graph
-            .node_data(&self.root_id.clone())
-            .unwrap_or(&Transform::new(0.0, 0.0, 0.0))
-            .clone();
-
-        traverse(
-            &self.graph,
-            &self.root_id,
-            root_transform,
-            &mut global_transforms,
-        );
-
-        global_transforms
+        self.graph
+            .propagate(&self.root_id, Transform::new(0.0, 0.0, 0.0), |parent, local| {
+                local.aggregate(parent)
+            })
     }
 }
 
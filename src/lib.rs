@@ -0,0 +1,232 @@
+//! A generic directed graph for representing hierarchical and relational data,
+//! such as scene graphs, skeletal rigs, and dependency trees.
+//!
+//! `NodeGraph` stores arbitrary node and edge payloads keyed by a caller-chosen
+//! `ID` type and leaves traversal, aggregation, and invalidation concerns to
+//! the modules in this crate rather than baking a single graph shape in.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+mod path;
+mod propagation;
+mod text;
+mod topology;
+mod traverse;
+
+pub use path::PathError;
+pub use propagation::PropagationCache;
+pub use text::ParseError;
+pub use topology::{CheckedEdgeError, CycleError};
+pub use traverse::{Ancestors, Bfs, Descendants, PostOrder, PreOrder};
+
+/// Bounds required of a node identifier across the whole crate.
+///
+/// `Ord` is required so that traversals and algorithms that need a
+/// deterministic iteration order (topological sort, pre/post-order walks)
+/// can sort node IDs instead of depending on `HashMap` iteration order.
+pub trait NodeId: Clone + Eq + Hash + Ord + Debug {}
+impl<T: Clone + Eq + Hash + Ord + Debug> NodeId for T {}
+
+/// Error returned when an edge cannot be added because one of its endpoints
+/// does not exist in the graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EdgeError<ID> {
+    /// The given node id has not been added to the graph.
+    NodeNotFound(ID),
+}
+
+impl<ID: Debug> std::fmt::Display for EdgeError<ID> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EdgeError::NodeNotFound(id) => write!(f, "node {id:?} does not exist in the graph"),
+        }
+    }
+}
+
+impl<ID: Debug> std::error::Error for EdgeError<ID> {}
+
+/// A directed graph of `NodeData` payloads connected by `EdgeData` payloads.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "ID: Serialize, EdgeData: Serialize, NodeData: Serialize",
+    deserialize = "ID: Deserialize<'de>, EdgeData: Deserialize<'de>, NodeData: Deserialize<'de>"
+))]
+pub struct NodeGraph<ID: NodeId, EdgeData, NodeData> {
+    nodes: HashMap<ID, NodeData>,
+    edges: HashMap<ID, Vec<(ID, EdgeData)>>,
+    paths: HashMap<String, Vec<ID>>,
+}
+
+impl<ID: NodeId, EdgeData, NodeData> Default for NodeGraph<ID, EdgeData, NodeData> {
+    fn default() -> Self {
+        Self {
+            nodes: HashMap::new(),
+            edges: HashMap::new(),
+            paths: HashMap::new(),
+        }
+    }
+}
+
+impl<ID: NodeId, EdgeData, NodeData> NodeGraph<ID, EdgeData, NodeData> {
+    /// Creates an empty graph.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts a node, replacing any existing data for that id.
+    pub fn add_node(&mut self, id: ID, data: NodeData) {
+        self.edges.entry(id.clone()).or_default();
+        self.nodes.insert(id, data);
+    }
+
+    /// Connects `from` to `to` with the given edge payload.
+    ///
+    /// Fails if either endpoint has not been added to the graph yet.
+    pub fn add_edge(&mut self, from: ID, to: ID, data: EdgeData) -> Result<(), EdgeError<ID>> {
+        if !self.nodes.contains_key(&from) {
+            return Err(EdgeError::NodeNotFound(from));
+        }
+        if !self.nodes.contains_key(&to) {
+            return Err(EdgeError::NodeNotFound(to));
+        }
+        self.edges.entry(from).or_default().push((to, data));
+        Ok(())
+    }
+
+    /// Returns the data stored for `id`, if it exists.
+    pub fn node_data(&self, id: &ID) -> Option<&NodeData> {
+        self.nodes.get(id)
+    }
+
+    /// Returns mutable access to the data stored for `id`, if it exists.
+    pub fn node_data_mut(&mut self, id: &ID) -> Option<&mut NodeData> {
+        self.nodes.get_mut(id)
+    }
+
+    /// Returns the outgoing edges of `id`, if the node exists.
+    pub fn get_edges_connected_to_node(&self, id: &ID) -> Option<Vec<(ID, EdgeData)>>
+    where
+        EdgeData: Clone,
+    {
+        self.edges.get(id).cloned()
+    }
+
+    /// Iterates over every node id currently in the graph.
+    pub fn node_ids(&self) -> impl Iterator<Item = &ID> {
+        self.nodes.keys()
+    }
+
+    /// Returns the number of nodes in the graph.
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Folds an accumulated value down from `root` through the graph,
+    /// visiting each reachable descendant exactly once.
+    ///
+    /// `combine` receives the parent's accumulated value and the child's own
+    /// `NodeData`, and produces the child's accumulated value. This is the
+    /// generic form of the root-to-leaf aggregation pattern used by scene
+    /// graphs to turn local transforms into global ones.
+    pub fn propagate<T, F>(&self, root: &ID, init: T, combine: F) -> HashMap<ID, T>
+    where
+        T: Clone,
+        F: Fn(&T, &NodeData) -> T,
+        EdgeData: Clone,
+    {
+        let mut results = HashMap::new();
+        // An explicit stack keeps this iterative instead of recursive, so a
+        // long chain (a flattened install order, a long bone chain) can't
+        // blow the call stack the way one recursive call per node would.
+        let mut stack = vec![(root.clone(), init)];
+
+        while let Some((node, parent)) = stack.pop() {
+            if results.contains_key(&node) {
+                // Already visited via another path (a diamond) or a cycle;
+                // don't recompute or descend again.
+                continue;
+            }
+
+            let Some(data) = self.node_data(&node) else {
+                continue;
+            };
+            let accumulated = combine(&parent, data);
+            results.insert(node.clone(), accumulated.clone());
+
+            if let Some(children) = self.get_edges_connected_to_node(&node) {
+                for (child_id, _) in children {
+                    stack.push((child_id, accumulated.clone()));
+                }
+            }
+        }
+
+        results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_node_and_edge_wires_up_adjacency() {
+        let mut graph: NodeGraph<u32, (), &str> = NodeGraph::new();
+        graph.add_node(1, "root");
+        graph.add_node(2, "child");
+        graph.add_edge(1, 2, ()).unwrap();
+
+        assert_eq!(graph.node_data(&2), Some(&"child"));
+        assert_eq!(graph.get_edges_connected_to_node(&1), Some(vec![(2, ())]));
+    }
+
+    #[test]
+    fn add_edge_rejects_missing_endpoints() {
+        let mut graph: NodeGraph<u32, (), &str> = NodeGraph::new();
+        graph.add_node(1, "root");
+        assert_eq!(graph.add_edge(1, 2, ()), Err(EdgeError::NodeNotFound(2)));
+    }
+
+    #[test]
+    fn propagate_accumulates_from_root() {
+        let mut graph: NodeGraph<u32, (), i32> = NodeGraph::new();
+        graph.add_node(1, 10);
+        graph.add_node(2, 20);
+        graph.add_edge(1, 2, ()).unwrap();
+
+        let result = graph.propagate(&1, 0, |parent, data| parent + data);
+        assert_eq!(result[&1], 10);
+        assert_eq!(result[&2], 30);
+    }
+
+    #[test]
+    fn propagate_does_not_loop_forever_on_a_cycle() {
+        let mut graph: NodeGraph<u32, (), i32> = NodeGraph::new();
+        graph.add_node(1, 10);
+        graph.add_node(2, 20);
+        graph.add_edge(1, 2, ()).unwrap();
+        graph.add_edge(2, 1, ()).unwrap();
+
+        let result = graph.propagate(&1, 0, |parent, data| parent + data);
+        assert_eq!(result.len(), 2);
+    }
+
+    #[test]
+    fn propagate_does_not_overflow_the_stack_on_a_long_chain() {
+        const LENGTH: u32 = 100_000;
+
+        let mut graph: NodeGraph<u32, (), i32> = NodeGraph::new();
+        for id in 0..LENGTH {
+            graph.add_node(id, 1);
+        }
+        for id in 0..LENGTH - 1 {
+            graph.add_edge(id, id + 1, ()).unwrap();
+        }
+
+        let result = graph.propagate(&0, 0, |parent, data| parent + data);
+        assert_eq!(result.len(), LENGTH as usize);
+        assert_eq!(result[&(LENGTH - 1)], LENGTH as i32);
+    }
+}
@@ -0,0 +1,150 @@
+//! Named paths: labelled, ordered walks through a [`NodeGraph`].
+//!
+//! A path is just a sequence of node ids that are pairwise connected by an
+//! edge, stored under a name so callers can refer to a meaningful route
+//! (a skeleton chain, a dependency install order, a scene instancing
+//! sequence) without rebuilding it from adjacency every time. Paths live on
+//! the graph itself and serialize along with it.
+
+use crate::{NodeGraph, NodeId};
+
+/// Error returned by [`NodeGraph::add_path`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathError<ID> {
+    /// A path must contain at least one node.
+    Empty,
+    /// A path with this name already exists.
+    AlreadyExists(String),
+    /// A node in the path was never added to the graph via `add_node`.
+    UnknownNode(ID),
+    /// Two consecutive nodes in the path are not connected by an edge.
+    Disconnected { from: ID, to: ID },
+}
+
+impl<ID: std::fmt::Debug> std::fmt::Display for PathError<ID> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PathError::Empty => write!(f, "a path must contain at least one node"),
+            PathError::AlreadyExists(name) => write!(f, "a path named {name:?} already exists"),
+            PathError::UnknownNode(id) => write!(f, "node {id:?} does not exist in the graph"),
+            PathError::Disconnected { from, to } => {
+                write!(f, "no edge connects {from:?} to {to:?}")
+            }
+        }
+    }
+}
+
+impl<ID: std::fmt::Debug> std::error::Error for PathError<ID> {}
+
+impl<ID: NodeId, EdgeData: Clone, NodeData> NodeGraph<ID, EdgeData, NodeData> {
+    /// Stores `ids` as a named path, after checking that every node exists
+    /// and each consecutive pair is connected by an edge.
+    pub fn add_path(&mut self, name: impl Into<String>, ids: &[ID]) -> Result<(), PathError<ID>> {
+        let name = name.into();
+        if ids.is_empty() {
+            return Err(PathError::Empty);
+        }
+        if self.paths.contains_key(&name) {
+            return Err(PathError::AlreadyExists(name));
+        }
+
+        for id in ids {
+            if self.node_data(id).is_none() {
+                return Err(PathError::UnknownNode(id.clone()));
+            }
+        }
+
+        for pair in ids.windows(2) {
+            let (from, to) = (&pair[0], &pair[1]);
+            let connected = self
+                .get_edges_connected_to_node(from)
+                .is_some_and(|edges| edges.iter().any(|(child, _)| child == to));
+            if !connected {
+                return Err(PathError::Disconnected {
+                    from: from.clone(),
+                    to: to.clone(),
+                });
+            }
+        }
+
+        self.paths.insert(name, ids.to_vec());
+        Ok(())
+    }
+
+    /// Returns the node sequence stored under `name`, if any.
+    pub fn path(&self, name: &str) -> Option<&[ID]> {
+        self.paths.get(name).map(Vec::as_slice)
+    }
+
+    /// Removes and returns the node sequence stored under `name`, if any.
+    pub fn remove_path(&mut self, name: &str) -> Option<Vec<ID>> {
+        self.paths.remove(name)
+    }
+
+    /// Returns the names of every path that visits `id`.
+    pub fn paths_through_node(&self, id: &ID) -> Vec<&str> {
+        self.paths
+            .iter()
+            .filter(|(_, ids)| ids.contains(id))
+            .map(|(name, _)| name.as_str())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_graph() -> NodeGraph<u32, (), ()> {
+        let mut graph = NodeGraph::new();
+        for id in [1, 2, 3] {
+            graph.add_node(id, ());
+        }
+        graph.add_edge(1, 2, ()).unwrap();
+        graph.add_edge(2, 3, ()).unwrap();
+        graph
+    }
+
+    #[test]
+    fn add_path_accepts_a_connected_sequence() {
+        let mut graph = sample_graph();
+        graph.add_path("chain", &[1, 2, 3]).unwrap();
+        assert_eq!(graph.path("chain"), Some([1, 2, 3].as_slice()));
+    }
+
+    #[test]
+    fn add_path_rejects_disconnected_nodes() {
+        let mut graph = sample_graph();
+        graph.add_node(99, ());
+        let err = graph.add_path("broken", &[1, 99]).unwrap_err();
+        assert_eq!(err, PathError::Disconnected { from: 1, to: 99 });
+    }
+
+    #[test]
+    fn add_path_rejects_a_node_that_was_never_added() {
+        let mut graph = sample_graph();
+        let err = graph.add_path("ghost", &[999]).unwrap_err();
+        assert_eq!(err, PathError::UnknownNode(999));
+        assert!(graph.paths_through_node(&999).is_empty());
+    }
+
+    #[test]
+    fn add_path_rejects_duplicate_names() {
+        let mut graph = sample_graph();
+        graph.add_path("chain", &[1, 2]).unwrap();
+        assert_eq!(
+            graph.add_path("chain", &[2, 3]).unwrap_err(),
+            PathError::AlreadyExists("chain".to_string())
+        );
+    }
+
+    #[test]
+    fn remove_path_and_paths_through_node() {
+        let mut graph = sample_graph();
+        graph.add_path("chain", &[1, 2, 3]).unwrap();
+        assert_eq!(graph.paths_through_node(&2), vec!["chain"]);
+
+        assert_eq!(graph.remove_path("chain"), Some(vec![1, 2, 3]));
+        assert!(graph.paths_through_node(&2).is_empty());
+    }
+}
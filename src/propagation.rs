@@ -0,0 +1,210 @@
+//! Opt-in caching for [`NodeGraph::propagate`](crate::NodeGraph::propagate).
+//!
+//! A [`PropagationCache`] remembers the last accumulated value computed for
+//! each node and only recomputes the subtree rooted at nodes that have been
+//! marked dirty, instead of folding the whole graph on every query. This
+//! mirrors how engines cache a node's `WorldTransform`: store the last
+//! result plus a dirty flag, and invalidate a node's whole subtree whenever
+//! something touches it.
+//!
+//! Structural mutations (`add_node`, `add_edge`) and payload mutations
+//! (`node_data_mut`) can change what a node's accumulated value should be,
+//! so callers are expected to call [`PropagationCache::invalidate`] with the
+//! affected node id immediately after making such a change.
+
+use crate::NodeId;
+use std::collections::{HashMap, HashSet};
+
+use crate::NodeGraph;
+
+#[derive(Clone)]
+struct Entry<T> {
+    value: T,
+    dirty: bool,
+}
+
+/// Caches the result of a [`NodeGraph::propagate`](crate::NodeGraph::propagate)
+/// fold, recomputing only the subtrees that have been invalidated since the
+/// last query.
+pub struct PropagationCache<ID, T> {
+    entries: HashMap<ID, Entry<T>>,
+}
+
+impl<ID: NodeId, T> Default for PropagationCache<ID, T> {
+    fn default() -> Self {
+        Self {
+            entries: HashMap::new(),
+        }
+    }
+}
+
+impl<ID: NodeId, T: Clone> PropagationCache<ID, T> {
+    /// Creates an empty cache. Every node is considered dirty until it has
+    /// been computed for the first time.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Marks `id` and every node reachable from it dirty, forcing them to be
+    /// recomputed on the next [`query`](Self::query). Call this right after
+    /// any `add_node`, `add_edge`, or `node_data_mut` call that could have
+    /// changed `id` or its descendants.
+    pub fn invalidate<EdgeData: Clone, NodeData>(
+        &mut self,
+        graph: &NodeGraph<ID, EdgeData, NodeData>,
+        id: &ID,
+    ) {
+        let mut stack = vec![id.clone()];
+        let mut seen = HashSet::new();
+        while let Some(current) = stack.pop() {
+            if !seen.insert(current.clone()) {
+                continue;
+            }
+            if let Some(entry) = self.entries.get_mut(&current) {
+                entry.dirty = true;
+            }
+            if let Some(children) = graph.get_edges_connected_to_node(&current) {
+                for (child_id, _) in children {
+                    stack.push(child_id);
+                }
+            }
+        }
+    }
+
+    /// Returns the accumulated value for every node reachable from `root`.
+    /// Clean nodes reuse their cached value as-is; dirty nodes (and only
+    /// dirty nodes) are recomputed from their parent's accumulated value,
+    /// so `combine` runs only for the touched subtree. The walk itself still
+    /// visits every node reachable from `root` on each query.
+    pub fn query<EdgeData, NodeData, F>(
+        &mut self,
+        graph: &NodeGraph<ID, EdgeData, NodeData>,
+        root: &ID,
+        init: T,
+        combine: F,
+    ) -> HashMap<ID, T>
+    where
+        EdgeData: Clone,
+        F: Fn(&T, &NodeData) -> T,
+    {
+        let mut visited = HashMap::new();
+        // An explicit stack avoids one recursive call per node, so a long
+        // chain can't blow the call stack during a query.
+        let mut stack = vec![(root.clone(), init)];
+
+        while let Some((node, parent)) = stack.pop() {
+            if visited.contains_key(&node) {
+                // Already visited this query via another path (a diamond)
+                // or a cycle; don't recompute or descend again.
+                continue;
+            }
+
+            let Some(data) = graph.node_data(&node) else {
+                continue;
+            };
+
+            let needs_recompute = match self.entries.get(&node) {
+                Some(entry) => entry.dirty,
+                None => true,
+            };
+
+            let accumulated = if needs_recompute {
+                let value = combine(&parent, data);
+                self.entries.insert(
+                    node.clone(),
+                    Entry {
+                        value: value.clone(),
+                        dirty: false,
+                    },
+                );
+                value
+            } else {
+                self.entries[&node].value.clone()
+            };
+
+            visited.insert(node.clone(), accumulated.clone());
+
+            if let Some(children) = graph.get_edges_connected_to_node(&node) {
+                for (child_id, _) in children {
+                    // A clean node's cached value is still a valid starting
+                    // point for its children even if we didn't recompute
+                    // it, since its own accumulated value hasn't changed.
+                    stack.push((child_id, accumulated.clone()));
+                }
+            }
+        }
+
+        visited
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_graph() -> NodeGraph<u32, (), i32> {
+        let mut graph = NodeGraph::new();
+        graph.add_node(1, 10);
+        graph.add_node(2, 20);
+        graph.add_edge(1, 2, ()).unwrap();
+        graph
+    }
+
+    #[test]
+    fn query_reuses_clean_values_and_recomputes_dirty_ones() {
+        let mut graph = sample_graph();
+        let mut cache = PropagationCache::new();
+
+        let first = cache.query(&graph, &1, 0, |p, d| p + d);
+        assert_eq!(first[&1], 10);
+        assert_eq!(first[&2], 30);
+
+        *graph.node_data_mut(&2).unwrap() = 200;
+        cache.invalidate(&graph, &2);
+
+        let second = cache.query(&graph, &1, 0, |p, d| p + d);
+        assert_eq!(second[&1], 10);
+        assert_eq!(second[&2], 210);
+    }
+
+    #[test]
+    fn query_only_returns_nodes_reachable_from_this_root() {
+        let mut graph = sample_graph();
+        graph.add_node(100, 1000);
+
+        let mut cache = PropagationCache::new();
+        cache.query(&graph, &1, 0, |p, d| p + d);
+
+        let from_other_root = cache.query(&graph, &100, 0, |p, d| p + d);
+        assert_eq!(from_other_root.len(), 1);
+        assert_eq!(from_other_root[&100], 1000);
+    }
+
+    #[test]
+    fn query_does_not_loop_forever_on_a_cycle() {
+        let mut graph = sample_graph();
+        graph.add_edge(2, 1, ()).unwrap();
+
+        let mut cache = PropagationCache::new();
+        let result = cache.query(&graph, &1, 0, |p, d| p + d);
+        assert_eq!(result.len(), 2);
+    }
+
+    #[test]
+    fn query_does_not_overflow_the_stack_on_a_long_chain() {
+        const LENGTH: u32 = 100_000;
+
+        let mut graph: NodeGraph<u32, (), i32> = NodeGraph::new();
+        for id in 0..LENGTH {
+            graph.add_node(id, 1);
+        }
+        for id in 0..LENGTH - 1 {
+            graph.add_edge(id, id + 1, ()).unwrap();
+        }
+
+        let mut cache = PropagationCache::new();
+        let result = cache.query(&graph, &0, 0, |p, d| p + d);
+        assert_eq!(result.len(), LENGTH as usize);
+        assert_eq!(result[&(LENGTH - 1)], LENGTH as i32);
+    }
+}
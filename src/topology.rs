@@ -0,0 +1,285 @@
+//! Cycle detection and topological ordering for directed graphs.
+
+use crate::{EdgeError, NodeGraph, NodeId};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Returned when a graph operation requires a DAG but the graph (or the
+/// edge being inserted) contains a cycle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleError<ID> {
+    /// The offending node ids, sorted. From [`NodeGraph::topological_sort`]
+    /// these are all the nodes that could not be ordered: every node on a
+    /// cycle plus any node that can only be reached through one. From
+    /// [`NodeGraph::add_edge_checked`] they are the nodes on the cycle the
+    /// rejected edge would have closed.
+    pub remaining: Vec<ID>,
+}
+
+impl<ID: std::fmt::Debug> std::fmt::Display for CycleError<ID> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "graph contains a cycle among nodes {:?}", self.remaining)
+    }
+}
+
+impl<ID: std::fmt::Debug> std::error::Error for CycleError<ID> {}
+
+/// Error returned by [`NodeGraph::add_edge_checked`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckedEdgeError<ID> {
+    /// The edge would close a cycle.
+    Cycle(CycleError<ID>),
+    /// One of the edge's endpoints does not exist in the graph.
+    MissingNode(EdgeError<ID>),
+}
+
+impl<ID: std::fmt::Debug> std::fmt::Display for CheckedEdgeError<ID> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CheckedEdgeError::Cycle(err) => write!(f, "{err}"),
+            CheckedEdgeError::MissingNode(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl<ID: std::fmt::Debug> std::error::Error for CheckedEdgeError<ID> {}
+
+impl<ID: NodeId, EdgeData: Clone, NodeData> NodeGraph<ID, EdgeData, NodeData> {
+    /// Computes a topological ordering of all nodes using Kahn's algorithm.
+    ///
+    /// Ties are broken by visiting node ids in `Ord` order, so the result is
+    /// deterministic for a given graph. Returns a [`CycleError`] naming the
+    /// nodes that could not be ordered if the graph is not a DAG.
+    pub fn topological_sort(&self) -> Result<Vec<ID>, CycleError<ID>> {
+        let mut in_degree: HashMap<ID, usize> = self.node_ids().map(|id| (id.clone(), 0)).collect();
+        for id in self.node_ids() {
+            if let Some(children) = self.get_edges_connected_to_node(id) {
+                for (child, _) in children {
+                    *in_degree.entry(child).or_insert(0) += 1;
+                }
+            }
+        }
+
+        let mut ready: Vec<ID> = in_degree
+            .iter()
+            .filter(|(_, degree)| **degree == 0)
+            .map(|(id, _)| id.clone())
+            .collect();
+        ready.sort();
+        let mut queue: VecDeque<ID> = ready.into();
+
+        let mut order = Vec::with_capacity(self.node_count());
+        while let Some(id) = queue.pop_front() {
+            order.push(id.clone());
+
+            let mut newly_ready = Vec::new();
+            if let Some(children) = self.get_edges_connected_to_node(&id) {
+                for (child, _) in children {
+                    let degree = in_degree.get_mut(&child).expect("child has an in-degree entry");
+                    *degree -= 1;
+                    if *degree == 0 {
+                        newly_ready.push(child);
+                    }
+                }
+            }
+            newly_ready.sort();
+            for id in newly_ready {
+                queue.push_back(id);
+            }
+        }
+
+        if order.len() < self.node_count() {
+            let mut remaining: Vec<ID> = in_degree
+                .into_iter()
+                .filter(|(_, degree)| *degree > 0)
+                .map(|(id, _)| id)
+                .collect();
+            remaining.sort();
+            return Err(CycleError { remaining });
+        }
+
+        Ok(order)
+    }
+
+    /// Returns the shortest path from `start` to `target` following
+    /// outgoing edges, if one exists.
+    fn shortest_path(&self, start: &ID, target: &ID) -> Option<Vec<ID>> {
+        if start == target {
+            return Some(vec![start.clone()]);
+        }
+
+        let mut queue = VecDeque::new();
+        queue.push_back(start.clone());
+        let mut parent: HashMap<ID, ID> = HashMap::new();
+        let mut seen = HashSet::new();
+        seen.insert(start.clone());
+
+        while let Some(current) = queue.pop_front() {
+            let mut children = self.successor_ids(&current).into_iter().collect::<Vec<_>>();
+            children.sort();
+
+            for child in children {
+                if !seen.insert(child.clone()) {
+                    continue;
+                }
+                parent.insert(child.clone(), current.clone());
+
+                if &child == target {
+                    let mut path = vec![child.clone()];
+                    let mut node = child;
+                    while let Some(p) = parent.get(&node) {
+                        path.push(p.clone());
+                        node = p.clone();
+                    }
+                    path.reverse();
+                    return Some(path);
+                }
+
+                queue.push_back(child);
+            }
+        }
+
+        None
+    }
+
+    /// Adds an edge the same way [`add_edge`](Self::add_edge) does, but
+    /// first rejects it if it would close a cycle, i.e. if `to` can already
+    /// reach `from`. The rejected edge's [`CycleError::remaining`] names
+    /// every node on the cycle it would have closed, not just its endpoints.
+    pub fn add_edge_checked(
+        &mut self,
+        from: ID,
+        to: ID,
+        data: EdgeData,
+    ) -> Result<(), CheckedEdgeError<ID>> {
+        if let Some(mut cycle) = self.shortest_path(&to, &from) {
+            cycle.sort();
+            return Err(CheckedEdgeError::Cycle(CycleError { remaining: cycle }));
+        }
+        self.add_edge(from, to, data)
+            .map_err(CheckedEdgeError::MissingNode)
+    }
+
+    /// Removes every edge that is implied by a longer path through the
+    /// graph, leaving the minimal set of edges with the same reachability.
+    ///
+    /// For example if `a->b`, `b->c`, and `a->c` all exist, `a->c` is
+    /// redundant since it's already implied by `a->b->c`, and is dropped.
+    /// Fails with [`CycleError`] if the graph is not a DAG, since transitive
+    /// reduction is only well-defined there.
+    pub fn transitive_reduction(&mut self) -> Result<(), CycleError<ID>> {
+        let order = self.topological_sort()?;
+
+        // Process nodes from sinks to sources so that every successor's
+        // reachability set is already known by the time we need it.
+        let mut reach: HashMap<ID, HashSet<ID>> = HashMap::new();
+        for id in order.iter().rev() {
+            let successors = self.successor_ids(id);
+            let mut set = HashSet::new();
+            for succ in &successors {
+                set.insert(succ.clone());
+                if let Some(succ_reach) = reach.get(succ) {
+                    set.extend(succ_reach.iter().cloned());
+                }
+            }
+            reach.insert(id.clone(), set);
+        }
+
+        for id in &order {
+            let successors = self.successor_ids(id);
+
+            let redundant: HashSet<ID> = successors
+                .iter()
+                .filter(|target| {
+                    successors.iter().any(|other| {
+                        other != *target
+                            && reach.get(other).is_some_and(|r| r.contains(*target))
+                    })
+                })
+                .cloned()
+                .collect();
+
+            if !redundant.is_empty() {
+                if let Some(edges) = self.edges.get_mut(id) {
+                    edges.retain(|(child, _)| !redundant.contains(child));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the distinct ids of `id`'s immediate successors.
+    fn successor_ids(&self, id: &ID) -> HashSet<ID> {
+        self.get_edges_connected_to_node(id)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(child, _)| child)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_dag() -> NodeGraph<u32, (), ()> {
+        let mut graph = NodeGraph::new();
+        for id in [1, 2, 3] {
+            graph.add_node(id, ());
+        }
+        graph.add_edge(1, 2, ()).unwrap();
+        graph.add_edge(2, 3, ()).unwrap();
+        graph
+    }
+
+    #[test]
+    fn topological_sort_orders_dependencies_before_dependents() {
+        let graph = sample_dag();
+        assert_eq!(graph.topological_sort().unwrap(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn topological_sort_reports_cycle_participants() {
+        let mut graph: NodeGraph<u32, (), ()> = NodeGraph::new();
+        graph.add_node(1, ());
+        graph.add_node(2, ());
+        graph.add_edge(1, 2, ()).unwrap();
+        graph.add_edge(2, 1, ()).unwrap();
+
+        let err = graph.topological_sort().unwrap_err();
+        assert_eq!(err.remaining, vec![1, 2]);
+    }
+
+    #[test]
+    fn add_edge_checked_rejects_edges_that_would_close_a_cycle() {
+        let mut graph = sample_dag();
+        let err = graph.add_edge_checked(3, 1, ()).unwrap_err();
+        match err {
+            CheckedEdgeError::Cycle(cycle) => assert_eq!(cycle.remaining, vec![1, 2, 3]),
+            other => panic!("expected a cycle error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn add_edge_checked_allows_non_cyclic_edges() {
+        let mut graph = sample_dag();
+        assert!(graph.add_edge_checked(1, 3, ()).is_ok());
+    }
+
+    #[test]
+    fn transitive_reduction_drops_redundant_direct_edges() {
+        let mut graph = sample_dag();
+        graph.add_edge(1, 3, ()).unwrap(); // redundant: already implied by 1->2->3
+
+        graph.transitive_reduction().unwrap();
+
+        let direct_children: Vec<u32> = graph
+            .get_edges_connected_to_node(&1)
+            .unwrap()
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        assert_eq!(direct_children, vec![2]);
+        assert_eq!(graph.topological_sort().unwrap(), vec![1, 2, 3]);
+    }
+}
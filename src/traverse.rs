@@ -0,0 +1,276 @@
+//! Lazy traversal iterators over a [`NodeGraph`].
+//!
+//! Each iterator here visits nodes on demand, step by step, rather than
+//! materializing the whole walk up front. Children are visited in `Ord`
+//! order for determinism, and every iterator tracks visited nodes so they
+//! stay safe to run on graphs that aren't strictly trees (shared nodes,
+//! diamonds, or cycles).
+
+use crate::{NodeGraph, NodeId};
+use std::collections::{HashSet, VecDeque};
+
+/// Depth-first pre-order traversal: a node is yielded before its children.
+pub struct PreOrder<'graph, ID: NodeId, EdgeData, NodeData> {
+    graph: &'graph NodeGraph<ID, EdgeData, NodeData>,
+    stack: Vec<ID>,
+    seen: HashSet<ID>,
+}
+
+impl<'graph, ID: NodeId, EdgeData: Clone, NodeData> Iterator for PreOrder<'graph, ID, EdgeData, NodeData> {
+    type Item = ID;
+
+    fn next(&mut self) -> Option<ID> {
+        while let Some(id) = self.stack.pop() {
+            if !self.seen.insert(id.clone()) {
+                continue;
+            }
+            let mut children = children_of(self.graph, &id);
+            children.reverse();
+            self.stack.extend(children);
+            return Some(id);
+        }
+        None
+    }
+}
+
+/// Depth-first post-order traversal: a node is yielded only after all of
+/// its children have been. Useful for bottom-up aggregation, such as
+/// fitting a bounding volume to a subtree.
+pub struct PostOrder<'graph, ID: NodeId, EdgeData, NodeData> {
+    graph: &'graph NodeGraph<ID, EdgeData, NodeData>,
+    // Each stack entry is a node together with whether its children have
+    // already been pushed for visiting.
+    stack: Vec<(ID, bool)>,
+    seen: HashSet<ID>,
+}
+
+impl<'graph, ID: NodeId, EdgeData: Clone, NodeData> Iterator for PostOrder<'graph, ID, EdgeData, NodeData> {
+    type Item = ID;
+
+    fn next(&mut self) -> Option<ID> {
+        while let Some((id, expanded)) = self.stack.pop() {
+            if expanded {
+                return Some(id);
+            }
+            if self.seen.contains(&id) {
+                continue;
+            }
+            self.seen.insert(id.clone());
+
+            self.stack.push((id.clone(), true));
+            let mut children = children_of(self.graph, &id);
+            children.reverse();
+            for child in children {
+                if !self.seen.contains(&child) {
+                    self.stack.push((child, false));
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Breadth-first traversal, yielding each node alongside its distance in
+/// edges from the root.
+pub struct Bfs<'graph, ID: NodeId, EdgeData, NodeData> {
+    graph: &'graph NodeGraph<ID, EdgeData, NodeData>,
+    queue: VecDeque<(ID, usize)>,
+    seen: HashSet<ID>,
+}
+
+impl<'graph, ID: NodeId, EdgeData: Clone, NodeData> Iterator for Bfs<'graph, ID, EdgeData, NodeData> {
+    type Item = (usize, ID);
+
+    fn next(&mut self) -> Option<(usize, ID)> {
+        while let Some((id, depth)) = self.queue.pop_front() {
+            if !self.seen.insert(id.clone()) {
+                continue;
+            }
+            for child in children_of(self.graph, &id) {
+                if !self.seen.contains(&child) {
+                    self.queue.push_back((child, depth + 1));
+                }
+            }
+            return Some((depth, id));
+        }
+        None
+    }
+}
+
+/// Iterates over the ancestors of a node, nearest first, by repeatedly
+/// finding a predecessor until none remain. Stops early if it revisits a
+/// node, so it terminates even if the graph has a cycle leading back to
+/// the start.
+pub struct Ancestors<'graph, ID: NodeId, EdgeData, NodeData> {
+    graph: &'graph NodeGraph<ID, EdgeData, NodeData>,
+    current: Option<ID>,
+    seen: HashSet<ID>,
+}
+
+impl<'graph, ID: NodeId, EdgeData: Clone, NodeData> Iterator for Ancestors<'graph, ID, EdgeData, NodeData> {
+    type Item = ID;
+
+    fn next(&mut self) -> Option<ID> {
+        let current = self.current.take()?;
+        let parent = self
+            .graph
+            .node_ids()
+            .filter(|candidate| {
+                self.graph
+                    .get_edges_connected_to_node(candidate)
+                    .is_some_and(|edges| edges.iter().any(|(child, _)| *child == current))
+            })
+            .min()
+            .cloned();
+
+        if let Some(parent) = &parent {
+            if !self.seen.insert(parent.clone()) {
+                self.current = None;
+                return None;
+            }
+        }
+        self.current = parent.clone();
+        parent
+    }
+}
+
+/// Iterates over every node reachable from a node, in pre-order, excluding
+/// the starting node itself.
+pub struct Descendants<'graph, ID: NodeId, EdgeData, NodeData> {
+    inner: PreOrder<'graph, ID, EdgeData, NodeData>,
+}
+
+impl<'graph, ID: NodeId, EdgeData: Clone, NodeData> Iterator for Descendants<'graph, ID, EdgeData, NodeData> {
+    type Item = ID;
+
+    fn next(&mut self) -> Option<ID> {
+        self.inner.next()
+    }
+}
+
+fn children_of<ID: NodeId, EdgeData: Clone, NodeData>(
+    graph: &NodeGraph<ID, EdgeData, NodeData>,
+    id: &ID,
+) -> Vec<ID> {
+    let mut children: Vec<ID> = graph
+        .get_edges_connected_to_node(id)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(child, _)| child)
+        .collect();
+    children.sort();
+    children.dedup();
+    children
+}
+
+impl<ID: NodeId, EdgeData: Clone, NodeData> NodeGraph<ID, EdgeData, NodeData> {
+    /// Depth-first pre-order traversal rooted at `root`.
+    pub fn pre_order(&self, root: &ID) -> PreOrder<'_, ID, EdgeData, NodeData> {
+        PreOrder {
+            graph: self,
+            stack: vec![root.clone()],
+            seen: HashSet::new(),
+        }
+    }
+
+    /// Depth-first post-order traversal rooted at `root`.
+    pub fn post_order(&self, root: &ID) -> PostOrder<'_, ID, EdgeData, NodeData> {
+        PostOrder {
+            graph: self,
+            stack: vec![(root.clone(), false)],
+            seen: HashSet::new(),
+        }
+    }
+
+    /// Breadth-first traversal rooted at `root`, yielding `(depth, id)`
+    /// pairs with `root` itself at depth `0`.
+    pub fn bfs(&self, root: &ID) -> Bfs<'_, ID, EdgeData, NodeData> {
+        let mut queue = VecDeque::new();
+        queue.push_back((root.clone(), 0));
+        Bfs {
+            graph: self,
+            queue,
+            seen: HashSet::new(),
+        }
+    }
+
+    /// Iterates over the ancestors of `id`, nearest first. When more than
+    /// one node has an edge to the current node, the smallest (by `Ord`)
+    /// is chosen so the walk is deterministic.
+    pub fn ancestors(&self, id: &ID) -> Ancestors<'_, ID, EdgeData, NodeData> {
+        Ancestors {
+            graph: self,
+            current: Some(id.clone()),
+            seen: HashSet::new(),
+        }
+    }
+
+    /// Iterates over every node reachable from `id`, excluding `id` itself.
+    pub fn descendants(&self, id: &ID) -> Descendants<'_, ID, EdgeData, NodeData> {
+        let mut inner = self.pre_order(id);
+        inner.next();
+        Descendants { inner }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_graph() -> NodeGraph<u32, (), ()> {
+        let mut graph = NodeGraph::new();
+        for id in [1, 2, 3, 4] {
+            graph.add_node(id, ());
+        }
+        graph.add_edge(1, 2, ()).unwrap();
+        graph.add_edge(1, 3, ()).unwrap();
+        graph.add_edge(2, 4, ()).unwrap();
+        graph
+    }
+
+    #[test]
+    fn pre_order_visits_parents_before_children() {
+        let graph = sample_graph();
+        assert_eq!(graph.pre_order(&1).collect::<Vec<_>>(), vec![1, 2, 4, 3]);
+    }
+
+    #[test]
+    fn post_order_visits_children_before_parents() {
+        let graph = sample_graph();
+        assert_eq!(graph.post_order(&1).collect::<Vec<_>>(), vec![4, 2, 3, 1]);
+    }
+
+    #[test]
+    fn bfs_yields_depth_alongside_each_node() {
+        let graph = sample_graph();
+        assert_eq!(
+            graph.bfs(&1).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 2), (1, 3), (2, 4)]
+        );
+    }
+
+    #[test]
+    fn descendants_excludes_the_starting_node() {
+        let graph = sample_graph();
+        assert_eq!(graph.descendants(&1).collect::<Vec<_>>(), vec![2, 4, 3]);
+    }
+
+    #[test]
+    fn ancestors_climbs_toward_the_root() {
+        let graph = sample_graph();
+        assert_eq!(graph.ancestors(&4).collect::<Vec<_>>(), vec![2, 1]);
+    }
+
+    #[test]
+    fn traversals_terminate_on_a_cycle() {
+        let mut graph: NodeGraph<u32, (), ()> = NodeGraph::new();
+        graph.add_node(1, ());
+        graph.add_node(2, ());
+        graph.add_edge(1, 2, ()).unwrap();
+        graph.add_edge(2, 1, ()).unwrap();
+
+        assert_eq!(graph.pre_order(&1).count(), 2);
+        assert_eq!(graph.post_order(&1).count(), 2);
+        assert_eq!(graph.bfs(&1).count(), 2);
+    }
+}